@@ -2,11 +2,11 @@
 //!
 //! # Examples
 //! ```rust
-//! # use embedded_hal::futures::digital::AsyncInputPin;
+//! # use embedded_hal::futures::digital::Wait;
 //! //! Asynchronously wait until the `ready_pin` becomes high.
-//! async fn wait_until_ready<P>(ready_pin: &P)
+//! async fn wait_until_ready<P>(ready_pin: &mut P)
 //! where
-//!     P: WaitFor,
+//!     P: Wait,
 //! {
 //!     ready_pin
 //!         .wait_for_high()
@@ -16,34 +16,101 @@
 //! ```
 //!
 //! ```rust,ignore
-//! # use embedded_hal::futures::digital::WaitForHigh;
+//! # use embedded_hal::futures::digital::WaitTimeout;
 //! # use embedded_hal::futures::delay::Delay;
 //! use core::time::Duration;
 //!
 //! //! Wait until the `ready_pin` is high or timeout after 1 millisecond.
 //! //! Returns true if the pin became high or false if it timed-out.
-//! async fn wait_until_ready_or_timeout<P, D>(ready_pin: &P, delay: &mut D) -> bool
+//! async fn wait_until_ready_or_timeout<P, D>(ready_pin: &mut P, delay: &mut D) -> bool
 //! where
-//!     P: WaitForHigh,
+//!     P: WaitTimeout,
 //!     D: Delay,
 //! {
-//!     futures::select_biased! {
-//!         x => ready_pin.wait_for_high() => {
-//!             x.expect("failed to await input pin");
-//!             true
-//!         },
-//!         _ => delay.delay(Duration::from_millis(1)) => false, // ignore the error
-//!     }
+//!     ready_pin
+//!         .wait_for_high_timeout(delay, Duration::from_millis(1))
+//!         .await
+//!         .expect("failed to await input pin")
 //! }
 //! ```
 
 use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
 
-/// Asynchronously wait for a pin to be high.
-pub trait WaitForHigh {
-    /// Enumeration of errors.
-    type Error;
+use crate::futures::delay::Delay;
+
+/// Error kinds for asynchronous digital I/O.
+///
+/// This represents a common set of operation-independent errors that an
+/// async GPIO pin implementation is expected to be able to distinguish, so
+/// that generic code can react to them (e.g. by retrying or falling back)
+/// without depending on a specific HAL's error type.
+///
+/// Implementations are free to define their own error type carrying more
+/// specific information, as long as it can be converted into this common
+/// set through the [`Error`] trait.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The hardware interrupt/event channel backing this wait is already in
+    /// use, for example by another pin or waiter.
+    InterruptChannelBusy,
+    /// This operation is not supported on the current hardware or
+    /// configuration.
+    Unsupported,
+    /// A different error occurred. The original error may contain more
+    /// information.
+    Other,
+}
+
+impl ErrorKind {
+    /// Converts this error kind into a human-readable string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::InterruptChannelBusy => {
+                "the interrupt/event channel needed for this wait is already in use"
+            }
+            Self::Unsupported => "this operation is not supported",
+            Self::Other => "a different error occurred, the original error may contain more information",
+        }
+    }
+}
+
+/// Async digital I/O error.
+pub trait Error: core::fmt::Debug {
+    /// Convert error to a generic error kind.
+    ///
+    /// By using this method, errors freely defined by HAL implementations
+    /// can be converted to a set of generic errors upon which generic code
+    /// can act.
+    fn kind(&self) -> ErrorKind;
+}
+
+impl Error for core::convert::Infallible {
+    fn kind(&self) -> ErrorKind {
+        match *self {}
+    }
+}
+
+impl Error for ErrorKind {
+    fn kind(&self) -> ErrorKind {
+        *self
+    }
+}
+
+/// Async digital I/O error type trait.
+///
+/// This just defines the error type, to be used by the other traits.
+pub trait ErrorType {
+    /// Error type.
+    type Error: Error;
+}
 
+/// Asynchronously wait for a pin to be high.
+#[deprecated(note = "use the `Wait` trait instead")]
+pub trait WaitForHigh: ErrorType {
     /// The future returned by the `wait_for_high` function.
     type WaitForHighFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
     where
@@ -59,10 +126,8 @@ pub trait WaitForHigh {
 }
 
 /// Asynchronously wait for a pin to be low.
-pub trait WaitForLow {
-    /// Enumeration of errors.
-    type Error;
-
+#[deprecated(note = "use the `Wait` trait instead")]
+pub trait WaitForLow: ErrorType {
     /// The future returned by `wait_for_low`.
     type WaitForLowFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
     where
@@ -78,10 +143,8 @@ pub trait WaitForLow {
 }
 
 /// Wait for a rising edge (transition from low to high).
-pub trait WaitForRisingEdge {
-    /// Enumeration of errors.
-    type Error;
-
+#[deprecated(note = "use the `Wait` trait instead")]
+pub trait WaitForRisingEdge: ErrorType {
     /// The future returned from `wait_for_rising_edge`.
     type WaitForRisingEdgeFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
     where
@@ -92,10 +155,8 @@ pub trait WaitForRisingEdge {
 }
 
 /// Wait for a falling edge (transition from high to low).
-pub trait WaitForFallingEdge {
-    /// Enumeration of errors.
-    type Error;
-
+#[deprecated(note = "use the `Wait` trait instead")]
+pub trait WaitForFallingEdge: ErrorType {
     /// The future returned from `wait_for_falling_edge`.
     type WaitForFallingEdgeFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
     where
@@ -106,9 +167,67 @@ pub trait WaitForFallingEdge {
 }
 
 /// Wait for any edge (transition from low to high OR high to low).
-pub trait WaitForAnyEdge {
-    /// Enumeration of errors.
-    type Error;
+#[deprecated(note = "use the `Wait` trait instead")]
+pub trait WaitForAnyEdge: ErrorType {
+    /// The future returned from `wait_for_any_edge`.
+    type WaitForAnyEdgeFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Returns a future that resolves when this pin undergoes any transition, e.g.
+    /// low to high OR high to low.
+    fn wait_for_any_edge<'a>(&'a mut self) -> Self::WaitForAnyEdgeFuture<'a>;
+}
+
+/// Asynchronously wait for a pin to change state.
+///
+/// This combines the capabilities of [`WaitForHigh`], [`WaitForLow`],
+/// [`WaitForRisingEdge`], [`WaitForFallingEdge`] and [`WaitForAnyEdge`] into a
+/// single trait sharing one `Error` type, since an implementation backed by a
+/// single GPIO peripheral almost always supports all five and fails in the
+/// same ways for each of them.
+pub trait Wait: ErrorType {
+    /// The future returned by the `wait_for_high` function.
+    type WaitForHighFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Returns a future that resolves when this pin _is_ high. If the pin
+    /// is already high, the future resolves immediately.
+    ///
+    /// # Note for implementers
+    /// The pin may have switched back to low before the task was run after
+    /// being woken. The future should still resolve in that case.
+    fn wait_for_high<'a>(&'a mut self) -> Self::WaitForHighFuture<'a>;
+
+    /// The future returned by `wait_for_low`.
+    type WaitForLowFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Returns a future that resolves when this pin _is_ low. If the pin
+    /// is already low, the future resolves immediately.
+    ///
+    /// # Note for implementers
+    /// The pin may have switched back to high before the task was run after
+    /// being woken. The future should still resolve in that case.
+    fn wait_for_low<'a>(&'a mut self) -> Self::WaitForLowFuture<'a>;
+
+    /// The future returned from `wait_for_rising_edge`.
+    type WaitForRisingEdgeFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Returns a future that resolves when this pin transitions from low to high.
+    fn wait_for_rising_edge<'a>(&'a mut self) -> Self::WaitForRisingEdgeFuture<'a>;
+
+    /// The future returned from `wait_for_falling_edge`.
+    type WaitForFallingEdgeFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Returns a future that resolves when this pin transitions from high to low.
+    fn wait_for_falling_edge<'a>(&'a mut self) -> Self::WaitForFallingEdgeFuture<'a>;
 
     /// The future returned from `wait_for_any_edge`.
     type WaitForAnyEdgeFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
@@ -119,3 +238,290 @@ pub trait WaitForAnyEdge {
     /// low to high OR high to low.
     fn wait_for_any_edge<'a>(&'a mut self) -> Self::WaitForAnyEdgeFuture<'a>;
 }
+
+/// Polarity an [`InputChannel`] is configured to react to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Polarity {
+    /// The channel does not react to pin transitions.
+    None,
+    /// The channel fires when the pin transitions from low to high.
+    LowToHigh,
+    /// The channel fires when the pin transitions from high to low.
+    HighToLow,
+    /// The channel fires on any pin transition.
+    Toggle,
+}
+
+/// An asynchronous input event channel, such as those exposed by
+/// GPIOTE-style peripherals.
+///
+/// Unlike the bare [`Wait`] futures, an `InputChannel` is backed by a
+/// dedicated hardware event line that is armed once for a given [`Polarity`]
+/// rather than re-configured on every call, so a driver can register for a
+/// single edge polarity and await it directly.
+pub trait InputChannel: ErrorType {
+    /// The future returned by `wait`.
+    type WaitFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Configures the polarity this channel reacts to.
+    fn set_polarity(&mut self, polarity: Polarity) -> Result<(), Self::Error>;
+
+    /// Returns a future that resolves the next time this channel's
+    /// configured polarity is observed.
+    fn wait<'a>(&'a mut self) -> Self::WaitFuture<'a>;
+}
+
+/// The action bound to an [`OutputChannel`]'s hardware task.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OutputPolarity {
+    /// Drive the pin high.
+    Set,
+    /// Drive the pin low.
+    Clear,
+    /// Flip the pin's current state.
+    Toggle,
+}
+
+/// An asynchronous output event channel, such as those exposed by
+/// GPIOTE-style peripherals.
+///
+/// An `OutputChannel` binds one [`OutputPolarity`] action to a hardware
+/// task, so that `set`/`clear`/`toggle` can be driven straight from an
+/// event line instead of only from interrupt-driven application code.
+pub trait OutputChannel: ErrorType {
+    /// Configures the action this channel's task performs when triggered.
+    fn set_polarity(&mut self, polarity: OutputPolarity) -> Result<(), Self::Error>;
+
+    /// Drives the pin high.
+    fn set(&mut self) -> Result<(), Self::Error>;
+
+    /// Drives the pin low.
+    fn clear(&mut self) -> Result<(), Self::Error>;
+
+    /// Flips the pin's current state.
+    fn toggle(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Races a wait future against a [`Delay`], resolving as soon as either one
+/// completes.
+///
+/// The pin's error is propagated as-is. The delay's error is ignored and
+/// treated as "the timeout fired", matching how every hand-rolled version of
+/// this race already treats it.
+pub struct Timeout<W, D> {
+    wait: W,
+    delay: D,
+}
+
+impl<W, D, E> Future for Timeout<W, D>
+where
+    W: Future<Output = Result<(), E>>,
+    D: Future,
+{
+    type Output = Result<bool, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: `wait` and `delay` are never moved once pinned; this is a
+        // standard structural-pinning projection.
+        let this = unsafe { self.get_unchecked_mut() };
+        let wait = unsafe { Pin::new_unchecked(&mut this.wait) };
+        let delay = unsafe { Pin::new_unchecked(&mut this.delay) };
+
+        if let Poll::Ready(result) = wait.poll(cx) {
+            return Poll::Ready(result.map(|()| true));
+        }
+        if delay.poll(cx).is_ready() {
+            return Poll::Ready(Ok(false));
+        }
+        Poll::Pending
+    }
+}
+
+/// Extension trait adding timeout support to [`Wait`].
+///
+/// Every method here races the underlying wait future against a [`Delay`],
+/// resolving to `Ok(true)` if the pin transitioned first or `Ok(false)` if
+/// the timeout elapsed first. This is exactly the `select_biased!` dance the
+/// module example used to spell out by hand, audited once instead of
+/// per-driver.
+pub trait WaitTimeout: Wait {
+    /// The future returned by `wait_for_high_timeout`.
+    type WaitForHighTimeoutFuture<'a, D>: Future<Output = Result<bool, Self::Error>> + 'a
+    where
+        Self: 'a,
+        D: Delay + 'a;
+
+    /// Waits for this pin to be high, or until `timeout` elapses.
+    fn wait_for_high_timeout<'a, D>(
+        &'a mut self,
+        delay: &'a mut D,
+        timeout: Duration,
+    ) -> Self::WaitForHighTimeoutFuture<'a, D>
+    where
+        D: Delay + 'a;
+
+    /// The future returned by `wait_for_low_timeout`.
+    type WaitForLowTimeoutFuture<'a, D>: Future<Output = Result<bool, Self::Error>> + 'a
+    where
+        Self: 'a,
+        D: Delay + 'a;
+
+    /// Waits for this pin to be low, or until `timeout` elapses.
+    fn wait_for_low_timeout<'a, D>(
+        &'a mut self,
+        delay: &'a mut D,
+        timeout: Duration,
+    ) -> Self::WaitForLowTimeoutFuture<'a, D>
+    where
+        D: Delay + 'a;
+
+    /// The future returned by `wait_for_rising_edge_timeout`.
+    type WaitForRisingEdgeTimeoutFuture<'a, D>: Future<Output = Result<bool, Self::Error>> + 'a
+    where
+        Self: 'a,
+        D: Delay + 'a;
+
+    /// Waits for this pin to see a rising edge, or until `timeout` elapses.
+    fn wait_for_rising_edge_timeout<'a, D>(
+        &'a mut self,
+        delay: &'a mut D,
+        timeout: Duration,
+    ) -> Self::WaitForRisingEdgeTimeoutFuture<'a, D>
+    where
+        D: Delay + 'a;
+
+    /// The future returned by `wait_for_falling_edge_timeout`.
+    type WaitForFallingEdgeTimeoutFuture<'a, D>: Future<Output = Result<bool, Self::Error>> + 'a
+    where
+        Self: 'a,
+        D: Delay + 'a;
+
+    /// Waits for this pin to see a falling edge, or until `timeout` elapses.
+    fn wait_for_falling_edge_timeout<'a, D>(
+        &'a mut self,
+        delay: &'a mut D,
+        timeout: Duration,
+    ) -> Self::WaitForFallingEdgeTimeoutFuture<'a, D>
+    where
+        D: Delay + 'a;
+
+    /// The future returned by `wait_for_any_edge_timeout`.
+    type WaitForAnyEdgeTimeoutFuture<'a, D>: Future<Output = Result<bool, Self::Error>> + 'a
+    where
+        Self: 'a,
+        D: Delay + 'a;
+
+    /// Waits for this pin to see any edge, or until `timeout` elapses.
+    fn wait_for_any_edge_timeout<'a, D>(
+        &'a mut self,
+        delay: &'a mut D,
+        timeout: Duration,
+    ) -> Self::WaitForAnyEdgeTimeoutFuture<'a, D>
+    where
+        D: Delay + 'a;
+}
+
+impl<W> WaitTimeout for W
+where
+    W: Wait,
+{
+    type WaitForHighTimeoutFuture<'a, D> = Timeout<W::WaitForHighFuture<'a>, D::DelayFuture<'a>>
+    where
+        Self: 'a,
+        D: Delay + 'a;
+
+    fn wait_for_high_timeout<'a, D>(
+        &'a mut self,
+        delay: &'a mut D,
+        timeout: Duration,
+    ) -> Self::WaitForHighTimeoutFuture<'a, D>
+    where
+        D: Delay + 'a,
+    {
+        Timeout {
+            wait: self.wait_for_high(),
+            delay: delay.delay(timeout),
+        }
+    }
+
+    type WaitForLowTimeoutFuture<'a, D> = Timeout<W::WaitForLowFuture<'a>, D::DelayFuture<'a>>
+    where
+        Self: 'a,
+        D: Delay + 'a;
+
+    fn wait_for_low_timeout<'a, D>(
+        &'a mut self,
+        delay: &'a mut D,
+        timeout: Duration,
+    ) -> Self::WaitForLowTimeoutFuture<'a, D>
+    where
+        D: Delay + 'a,
+    {
+        Timeout {
+            wait: self.wait_for_low(),
+            delay: delay.delay(timeout),
+        }
+    }
+
+    type WaitForRisingEdgeTimeoutFuture<'a, D> =
+        Timeout<W::WaitForRisingEdgeFuture<'a>, D::DelayFuture<'a>>
+    where
+        Self: 'a,
+        D: Delay + 'a;
+
+    fn wait_for_rising_edge_timeout<'a, D>(
+        &'a mut self,
+        delay: &'a mut D,
+        timeout: Duration,
+    ) -> Self::WaitForRisingEdgeTimeoutFuture<'a, D>
+    where
+        D: Delay + 'a,
+    {
+        Timeout {
+            wait: self.wait_for_rising_edge(),
+            delay: delay.delay(timeout),
+        }
+    }
+
+    type WaitForFallingEdgeTimeoutFuture<'a, D> =
+        Timeout<W::WaitForFallingEdgeFuture<'a>, D::DelayFuture<'a>>
+    where
+        Self: 'a,
+        D: Delay + 'a;
+
+    fn wait_for_falling_edge_timeout<'a, D>(
+        &'a mut self,
+        delay: &'a mut D,
+        timeout: Duration,
+    ) -> Self::WaitForFallingEdgeTimeoutFuture<'a, D>
+    where
+        D: Delay + 'a,
+    {
+        Timeout {
+            wait: self.wait_for_falling_edge(),
+            delay: delay.delay(timeout),
+        }
+    }
+
+    type WaitForAnyEdgeTimeoutFuture<'a, D> =
+        Timeout<W::WaitForAnyEdgeFuture<'a>, D::DelayFuture<'a>>
+    where
+        Self: 'a,
+        D: Delay + 'a;
+
+    fn wait_for_any_edge_timeout<'a, D>(
+        &'a mut self,
+        delay: &'a mut D,
+        timeout: Duration,
+    ) -> Self::WaitForAnyEdgeTimeoutFuture<'a, D>
+    where
+        D: Delay + 'a,
+    {
+        Timeout {
+            wait: self.wait_for_any_edge(),
+            delay: delay.delay(timeout),
+        }
+    }
+}